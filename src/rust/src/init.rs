@@ -1,49 +1,100 @@
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Once;
 
+use once_cell::sync::OnceCell;
+use orchard::circuit::{ProvingKey, VerifyingKey};
 use tracing::info;
 
-use crate::{ORCHARD_PK, ORCHARD_VK};
-
 #[cxx::bridge]
 mod ffi {
     #[namespace = "init"]
     extern "Rust" {
-        fn rayon_threadpool();
+        fn rayon_threadpool(num_threads: usize, thread_name_prefix: String) -> bool;
         fn zksnark_params(sprout_path: String, load_proving_keys: bool);
     }
 }
 
+/// Errors that can occur while accessing the Orchard zk-SNARK parameters.
+#[derive(Debug, thiserror::Error)]
+pub enum ParamsError {
+    #[error("orchard verifying key not loaded; call zksnark_params first")]
+    VerifyingKeyNotLoaded,
+    #[error("proof parameters not loaded; call zksnark_params with load_proving_keys=true")]
+    ProvingKeyNotLoaded,
+}
+
 static PROOF_PARAMETERS_LOADED: Once = Once::new();
 
-fn rayon_threadpool() {
+/// Whether `zksnark_params` was called with `load_proving_keys = true`. Only
+/// meaningful once `PROOF_PARAMETERS_LOADED` has fired.
+static PROVING_KEYS_PERMITTED: AtomicBool = AtomicBool::new(false);
+
+// The verifying key is comparatively cheap to build, but the proving key is the most
+// expensive part of startup. Both are built lazily, on first actual use, rather than
+// up front in `zksnark_params` (mirroring the lazy-load approach used for the gtest
+// parameters), so that verify-only nodes never pay the proving key's build cost.
+static ORCHARD_PK: OnceCell<ProvingKey> = OnceCell::new();
+static ORCHARD_VK: OnceCell<VerifyingKey> = OnceCell::new();
+
+/// Returns the Orchard verifying key, building it on first use.
+pub fn orchard_verifying_key() -> Result<&'static VerifyingKey, ParamsError> {
+    if !PROOF_PARAMETERS_LOADED.is_completed() {
+        return Err(ParamsError::VerifyingKeyNotLoaded);
+    }
+    Ok(ORCHARD_VK.get_or_init(VerifyingKey::build))
+}
+
+/// Returns the Orchard proving key, building it on first use.
+///
+/// Returns `Err(ParamsError::ProvingKeyNotLoaded)` if `zksnark_params` was called with
+/// `load_proving_keys = false` (e.g. from the Boost test suite), since proving is not
+/// permitted in that case.
+pub fn orchard_proving_key() -> Result<&'static ProvingKey, ParamsError> {
+    if !PROVING_KEYS_PERMITTED.load(Ordering::SeqCst) {
+        return Err(ParamsError::ProvingKeyNotLoaded);
+    }
+    Ok(ORCHARD_PK.get_or_init(ProvingKey::build))
+}
+
+/// Initializes the global rayon thread pool.
+///
+/// `num_threads` bounds how many worker threads rayon may use; `0` preserves the
+/// default behaviour of detecting the number of available cores. `thread_name_prefix`
+/// is used to name the worker threads (`"{prefix}-{index}"`); an empty prefix falls
+/// back to the existing `"zc-rayon"` naming.
+///
+/// Returns `true` on success, or `false` if the global thread pool was already
+/// initialized (e.g. by a second call to this function), rather than panicking across
+/// the FFI boundary.
+fn rayon_threadpool(num_threads: usize, thread_name_prefix: String) -> bool {
+    let prefix = if thread_name_prefix.is_empty() {
+        "zc-rayon".to_string()
+    } else {
+        thread_name_prefix
+    };
+
     rayon::ThreadPoolBuilder::new()
-        .thread_name(|i| format!("zc-rayon-{}", i))
+        .num_threads(num_threads)
+        .thread_name(move |i| format!("{}-{}", prefix, i))
         .build_global()
-        .expect("Only initialized once");
+        .is_ok()
 }
 
-/// Loads the zk-SNARK parameters into memory (Orchard-only chain).
+/// Records that the zk-SNARK parameters are ready to be used (Orchard-only chain).
 /// Only called once.
 ///
-/// If `load_proving_keys` is `false`, the proving keys will not be loaded, making it
-/// impossible to create proofs. This flag is for the Boost test suite.
+/// This does not itself build the Orchard verifying/proving keys; they are built
+/// lazily on first use, via `orchard_verifying_key`/`orchard_proving_key`.
+///
+/// If `load_proving_keys` is `false`, proving is not permitted, making it impossible
+/// to create proofs. This flag is for the Boost test suite.
 ///
 /// The sprout_path parameter is kept for API compatibility but is unused on Orchard-only chain.
 fn zksnark_params(_sprout_path: String, load_proving_keys: bool) {
     PROOF_PARAMETERS_LOADED.call_once(|| {
         // Juno Cash: Orchard-only chain - only load Orchard parameters
         // Sprout and Sapling parameters are not loaded as they are banned at consensus level
-
-        // Generate Orchard parameters.
-        info!(target: "main", "Loading Orchard parameters");
-        let orchard_pk = load_proving_keys.then(orchard::circuit::ProvingKey::build);
-        let orchard_vk = orchard::circuit::VerifyingKey::build();
-
-        // Caller is responsible for calling this function once, so
-        // these global mutations are safe.
-        unsafe {
-            ORCHARD_PK = orchard_pk;
-            ORCHARD_VK = Some(orchard_vk);
-        }
+        info!(target: "main", "Orchard parameters ready (built lazily on first use)");
+        PROVING_KEYS_PERMITTED.store(load_proving_keys, Ordering::SeqCst);
     });
 }
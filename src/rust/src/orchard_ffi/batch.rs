@@ -0,0 +1,62 @@
+use rand_core::OsRng;
+use tracing::error;
+
+use crate::init::orchard_verifying_key;
+use crate::orchard_ffi::ffi::OrchardBundlePtr;
+
+#[cxx::bridge]
+mod ffi {
+    // Bring in the orchard_ffi bridge's own binding of the type rather than
+    // re-declaring `type OrchardBundlePtr;` here, which would generate a second,
+    // unrelated opaque type that none of `OrchardBundlePtr`'s inherent methods apply to.
+    use super::OrchardBundlePtr;
+
+    #[namespace = "orchard_bundle"]
+    extern "Rust" {
+        type BatchValidator;
+
+        fn orchard_batch_validation_init() -> Box<BatchValidator>;
+        fn add_bundle(self: &mut BatchValidator, bundle: &OrchardBundlePtr, sighash: [u8; 32]);
+        fn validate(self: &mut BatchValidator) -> bool;
+    }
+}
+
+/// Accumulates Orchard bundles across a block (or set of transactions) for a single
+/// combined batch-validation pass, covering both the RedPallas signatures and the
+/// Halo 2 proofs. All-or-nothing: a failing batch does not identify or retry the
+/// individual bundle that failed.
+#[derive(Default)]
+pub struct BatchValidator(Option<orchard::bundle::BatchValidator>);
+
+impl BatchValidator {
+    fn add_bundle(&mut self, bundle: &OrchardBundlePtr, sighash: [u8; 32]) {
+        if let Some(bundle) = bundle.inner() {
+            self.0
+                .get_or_insert_with(orchard::bundle::BatchValidator::new)
+                .add_bundle(bundle, sighash);
+        }
+    }
+
+    fn validate(&mut self) -> bool {
+        let Some(batch) = self.0.take() else {
+            // No bundles were queued; trivially valid.
+            return true;
+        };
+
+        let vk = match orchard_verifying_key() {
+            Ok(vk) => vk,
+            Err(e) => {
+                error!(target: "main", "Orchard batch validation failed: {}", e);
+                return false;
+            }
+        };
+
+        // `orchard::bundle::BatchValidator::validate` verifies both the accumulated
+        // RedPallas signatures and the Halo 2 proofs in a single batched pass.
+        batch.validate(vk, OsRng)
+    }
+}
+
+fn orchard_batch_validation_init() -> Box<BatchValidator> {
+    Box::new(BatchValidator::default())
+}